@@ -8,35 +8,90 @@ pub fn init() {
 }
 
 /// Sieve of Eratosthenes - highly optimized for generating primes up to limit
+///
+/// Internally this only tracks odd candidates, packed one bit per number, so
+/// memory use is ~16x smaller than a byte-per-number sieve and evens are
+/// never visited.
 #[wasm_bindgen]
 pub fn sieve_of_eratosthenes(limit: usize) -> Vec<u32> {
     if limit < 2 {
         return vec![];
     }
-    
-    // Use bit array for memory efficiency
-    let mut is_prime = vec![true; limit + 1];
-    is_prime[0] = false;
-    is_prime[1] = false;
-    
+    if limit == 2 {
+        return vec![2];
+    }
+
+    // Bit `ndx` represents the odd candidate `2*ndx + 3`, packed 32 to a word.
+    let ndxlmt = (limit - 3) / 2 + 1;
+    let mut cmpsts = vec![0u32; (ndxlmt - 1) / 32 + 1];
+
     let sqrt_limit = (limit as f64).sqrt() as usize;
-    
-    for i in 2..=sqrt_limit {
-        if is_prime[i] {
-            // Mark all multiples of i as composite
-            let mut j = i * i;
-            while j <= limit {
-                is_prime[j] = false;
-                j += i;
+    if sqrt_limit >= 3 {
+        let ndx_sqrt_lmt = (sqrt_limit - 3) / 2;
+        for ndx in 0..=ndx_sqrt_lmt {
+            if cmpsts[ndx >> 5] & (1 << (ndx & 31)) == 0 {
+                let p = 2 * ndx + 3;
+                let mut cullpos = (p * p - 3) / 2;
+                while cullpos < ndxlmt {
+                    cmpsts[cullpos >> 5] |= 1 << (cullpos & 31);
+                    cullpos += p;
+                }
             }
         }
     }
-    
-    // Collect primes
-    is_prime
+
+    // Collect primes: 2 first, then every clear bit as 2*i+3
+    let mut primes = Vec::with_capacity(ndxlmt + 1);
+    primes.push(2);
+    for i in 0..ndxlmt {
+        if cmpsts[i >> 5] & (1 << (i & 31)) == 0 {
+            primes.push((2 * i + 3) as u32);
+        }
+    }
+    primes
+}
+
+/// Segmented sieve: list primes in `[low, high)` without sieving from zero
+///
+/// Memory is proportional to the window size (`high - low`) rather than to
+/// `high` itself, so windows far out on the number line (e.g. around 1e9)
+/// stay cheap to explore.
+#[wasm_bindgen]
+pub fn sieve_range(low: u64, high: u64) -> Vec<u64> {
+    if high <= low {
+        return vec![];
+    }
+
+    let sqrt_high = (high as f64).sqrt() as usize;
+    let base_primes = sieve_of_eratosthenes(sqrt_high);
+
+    let seg_len = (high - low) as usize;
+    let mut composite = vec![false; seg_len];
+
+    for &p in &base_primes {
+        let p = p as u64;
+        let start = std::cmp::max(p * p, ((low + p - 1) / p) * p);
+        if start >= high {
+            continue;
+        }
+        let mut cullpos = start - low;
+        while (cullpos as usize) < seg_len {
+            composite[cullpos as usize] = true;
+            cullpos += p;
+        }
+    }
+
+    composite
         .iter()
         .enumerate()
-        .filter_map(|(i, &is_p)| if is_p { Some(i as u32) } else { None })
+        .filter_map(|(i, &is_c)| {
+            let value = low + i as u64;
+            if !is_c && value >= 2 {
+                Some(value)
+            } else {
+                None
+            }
+        })
         .collect()
 }
 
@@ -82,6 +137,30 @@ pub fn prime_gaps(primes: &[u32]) -> Vec<u32> {
         .collect()
 }
 
+/// Primes up to `limit` whose decimal digit sum is itself prime (2, 3, 5, 7, 11, ...)
+#[wasm_bindgen]
+pub fn additive_primes(limit: usize) -> Vec<u32> {
+    sieve_of_eratosthenes(limit)
+        .into_iter()
+        .filter(|&p| is_prime(digit_sum(p)))
+        .collect()
+}
+
+/// Digit sum of each prime, so the visualization can color primes by digit-sum class
+#[wasm_bindgen]
+pub fn digit_sums(primes: &[u32]) -> Vec<u32> {
+    primes.iter().map(|&p| digit_sum(p)).collect()
+}
+
+fn digit_sum(mut n: u32) -> u32 {
+    let mut s = 0;
+    while n > 0 {
+        s += n % 10;
+        n /= 10;
+    }
+    s
+}
+
 /// Check if a single number is prime
 #[wasm_bindgen]
 pub fn is_prime(n: u32) -> bool {
@@ -106,6 +185,66 @@ pub fn is_prime(n: u32) -> bool {
     true
 }
 
+/// Deterministic Miller-Rabin primality test, valid for all `n < 2^64`
+#[wasm_bindgen]
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n == 2 || n == 3 {
+        return true;
+    }
+    if n % 2 == 0 {
+        return false;
+    }
+
+    // n - 1 = d * 2^s, with d odd
+    let mut d = n - 1;
+    let mut s = 0;
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+
+    // Witnesses sufficient to be deterministic for all n < 2^64
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    'witness: for &a in &WITNESSES {
+        if a % n == 0 {
+            continue;
+        }
+
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+
+        for _ in 0..s - 1 {
+            x = (x as u128 * x as u128 % n as u128) as u64;
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+fn mod_pow(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1u64;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result as u128 * base as u128 % modulus as u128) as u64;
+        }
+        exp >>= 1;
+        base = (base as u128 * base as u128 % modulus as u128) as u64;
+    }
+    result
+}
+
 /// Get prime factorization of a number
 #[wasm_bindgen]
 pub fn prime_factorization(mut n: u32) -> Vec<u32> {
@@ -136,6 +275,49 @@ pub fn prime_factorization(mut n: u32) -> Vec<u32> {
     factors
 }
 
+/// Build a smallest-prime-factor table up to `limit` with a linear (Euler) sieve
+///
+/// Unlike `sieve_of_eratosthenes`, each composite is marked exactly once, in
+/// O(limit) total. The table also cheaply yields Euler's totient and divisor
+/// counts for future visualizations.
+#[wasm_bindgen]
+pub fn smallest_prime_factors(limit: usize) -> Vec<u32> {
+    let mut spf = vec![0u32; limit + 1];
+    let mut primes = Vec::new();
+
+    for i in 2..=limit {
+        if spf[i] == 0 {
+            spf[i] = i as u32;
+            primes.push(i as u32);
+        }
+        for &p in &primes {
+            if p > spf[i] || i * p as usize > limit {
+                break;
+            }
+            spf[i * p as usize] = p;
+            if i % p as usize == 0 {
+                break;
+            }
+        }
+    }
+
+    spf
+}
+
+/// Factor `n` in O(log n) using a precomputed smallest-prime-factor table
+#[wasm_bindgen]
+pub fn factorize_with_spf(mut n: u32, spf: &[u32]) -> Vec<u32> {
+    let mut factors = Vec::new();
+
+    while n > 1 {
+        let p = spf[n as usize];
+        factors.push(p);
+        n /= p;
+    }
+
+    factors
+}
+
 /// Count primes up to each value (prime counting function)
 #[wasm_bindgen]
 pub fn prime_counting(limit: usize) -> Vec<u32> {